@@ -0,0 +1,60 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::fs::File;
+use std::io;
+use std::io::Write;
+
+/// errors from the bincode-backed `save_bin`/`load_bin`/`load_auto` config codec
+#[derive(Debug)]
+pub enum BinCodecError {
+    Io(io::Error),
+    BadMagic,
+    Bincode(Box<bincode::ErrorKind>),
+}
+
+impl fmt::Display for BinCodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinCodecError::Io(err) => write!(f, "failed to access config file: {err}"),
+            BinCodecError::BadMagic => {
+                write!(f, "file does not start with the expected magic bytes")
+            }
+            BinCodecError::Bincode(err) => write!(f, "failed to decode bincode config: {err}"),
+        }
+    }
+}
+
+/// serializes `value` with bincode and writes it to `path` behind `magic`
+pub fn write_magic_bincode<T: Serialize>(
+    path: &str,
+    magic: &[u8],
+    value: &T,
+) -> Result<(), BinCodecError> {
+    let payload = bincode::serialize(value).expect("config must always be serializable");
+
+    let mut file = File::create(path).map_err(BinCodecError::Io)?;
+    file.write_all(magic).map_err(BinCodecError::Io)?;
+    file.write_all(&payload).map_err(BinCodecError::Io)?;
+    Ok(())
+}
+
+/// whether the file at `path` starts with `magic`
+pub fn has_magic(path: &str, magic: &[u8]) -> io::Result<bool> {
+    let data = fs::read(path)?;
+    Ok(data.len() >= magic.len() && data[..magic.len()] == *magic)
+}
+
+/// reads and bincode-deserializes a file written by [`write_magic_bincode`] with the same `magic`
+pub fn read_magic_bincode<T: DeserializeOwned>(
+    path: &str,
+    magic: &[u8],
+) -> Result<T, BinCodecError> {
+    let data = fs::read(path).map_err(BinCodecError::Io)?;
+    if data.len() < magic.len() || data[..magic.len()] != *magic {
+        return Err(BinCodecError::BadMagic);
+    }
+
+    bincode::deserialize(&data[magic.len()..]).map_err(BinCodecError::Bincode)
+}
@@ -0,0 +1,159 @@
+use super::prev::{v1_0::GenerationConfigV1_0, v1_1::GenerationConfigV1_1};
+use super::GenerationConfig;
+use serde_json::Value;
+
+/// current `GenerationConfig` schema version
+pub const CURRENT_VERSION: &str = "1.2";
+
+/// runs `value` through every migration step needed to reach [`CURRENT_VERSION`], returning the
+/// migrated value along with the ordered list of steps applied, e.g. `["1.0 -> 1.1"]`
+pub fn migrate(mut value: Value) -> Result<(Value, Vec<String>), serde_json::Error> {
+    let mut applied = Vec::new();
+
+    loop {
+        let version = value
+            .get("version")
+            .and_then(Value::as_str)
+            .unwrap_or("1.0")
+            .to_string();
+
+        value = match version.as_str() {
+            "1.0" => {
+                applied.push("1.0 -> 1.1".to_string());
+                migrate_v1_0_to_v1_1(value)?
+            }
+            "1.1" => {
+                applied.push("1.1 -> 1.2".to_string());
+                migrate_v1_1_to_v1_2(value)?
+            }
+            _ => break,
+        };
+    }
+
+    Ok((value, applied))
+}
+
+/// v1.1 added `plat_soft_overhang` and the pulse fields, defaulted off for older presets
+fn migrate_v1_0_to_v1_1(value: Value) -> Result<Value, serde_json::Error> {
+    let old = serde_json::from_value::<GenerationConfigV1_0>(value)?;
+    let defaults = GenerationConfigV1_1::default();
+
+    let new = GenerationConfigV1_1 {
+        name: old.name,
+        description: old.description,
+        version: "1.1".to_string(),
+        inner_rad_mut_prob: old.inner_rad_mut_prob,
+        inner_size_mut_prob: old.inner_size_mut_prob,
+        outer_rad_mut_prob: old.outer_rad_mut_prob,
+        outer_size_mut_prob: old.outer_size_mut_prob,
+        shift_weights: old.shift_weights,
+        plat_min_distance: old.plat_min_distance,
+        plat_width_bounds: old.plat_width_bounds,
+        plat_height_bounds: old.plat_height_bounds,
+        plat_min_empty_height: old.plat_min_empty_height,
+        plat_soft_overhang: defaults.plat_soft_overhang,
+        momentum_prob: old.momentum_prob,
+        max_distance: old.max_distance,
+        waypoint_reached_dist: old.waypoint_reached_dist,
+        inner_size_probs: old.inner_size_probs,
+        outer_margin_probs: old.outer_margin_probs,
+        circ_probs: old.circ_probs,
+        skip_length_bounds: old.skip_length_bounds,
+        skip_min_spacing_sqr: old.skip_min_spacing_sqr,
+        max_level_skip: old.max_level_skip,
+        min_freeze_size: old.min_freeze_size,
+        enable_pulse: defaults.enable_pulse,
+        pulse_straight_delay: defaults.pulse_straight_delay,
+        pulse_corner_delay: defaults.pulse_corner_delay,
+        pulse_max_kernel_size: defaults.pulse_max_kernel_size,
+        fade_steps: old.fade_steps,
+        fade_max_size: old.fade_max_size,
+        fade_min_size: old.fade_min_size,
+        max_subwaypoint_dist: old.max_subwaypoint_dist,
+        subwaypoint_max_shift_dist: old.subwaypoint_max_shift_dist,
+    };
+
+    Ok(serde_json::to_value(new).expect("serializing a frozen config schema cannot fail"))
+}
+
+/// v1.2 added position locking (`pos_lock_max_dist`, `pos_lock_max_delay`, `lock_kernel_size`)
+fn migrate_v1_1_to_v1_2(value: Value) -> Result<Value, serde_json::Error> {
+    let old = serde_json::from_value::<GenerationConfigV1_1>(value)?;
+    let defaults = GenerationConfig::default();
+
+    let new = GenerationConfig {
+        name: old.name,
+        description: old.description,
+        version: CURRENT_VERSION.to_string(),
+        inner_rad_mut_prob: old.inner_rad_mut_prob,
+        inner_size_mut_prob: old.inner_size_mut_prob,
+        outer_rad_mut_prob: old.outer_rad_mut_prob,
+        outer_size_mut_prob: old.outer_size_mut_prob,
+        shift_weights: old.shift_weights,
+        plat_min_distance: old.plat_min_distance,
+        plat_width_bounds: old.plat_width_bounds,
+        plat_height_bounds: old.plat_height_bounds,
+        plat_min_empty_height: old.plat_min_empty_height,
+        plat_soft_overhang: old.plat_soft_overhang,
+        momentum_prob: old.momentum_prob,
+        max_distance: old.max_distance,
+        waypoint_reached_dist: old.waypoint_reached_dist,
+        inner_size_probs: old.inner_size_probs,
+        outer_margin_probs: old.outer_margin_probs,
+        circ_probs: old.circ_probs,
+        skip_length_bounds: old.skip_length_bounds,
+        skip_min_spacing_sqr: old.skip_min_spacing_sqr,
+        max_level_skip: old.max_level_skip,
+        min_freeze_size: old.min_freeze_size,
+        enable_pulse: old.enable_pulse,
+        pulse_straight_delay: old.pulse_straight_delay,
+        pulse_corner_delay: old.pulse_corner_delay,
+        pulse_max_kernel_size: old.pulse_max_kernel_size,
+        fade_steps: old.fade_steps,
+        fade_max_size: old.fade_max_size,
+        fade_min_size: old.fade_min_size,
+        max_subwaypoint_dist: old.max_subwaypoint_dist,
+        subwaypoint_max_shift_dist: old.subwaypoint_max_shift_dist,
+        pos_lock_max_dist: defaults.pos_lock_max_dist,
+        pos_lock_max_delay: defaults.pos_lock_max_delay,
+        lock_kernel_size: defaults.lock_kernel_size,
+    };
+
+    Ok(serde_json::to_value(new).expect("serializing a frozen config schema cannot fail"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v1_0_all_the_way_to_current() {
+        let value = serde_json::to_value(GenerationConfigV1_0::default()).unwrap();
+
+        let (migrated, applied) = migrate(value).unwrap();
+
+        assert_eq!(applied, vec!["1.0 -> 1.1", "1.1 -> 1.2"]);
+        assert_eq!(
+            migrated.get("version").and_then(Value::as_str),
+            Some(CURRENT_VERSION)
+        );
+    }
+
+    #[test]
+    fn leaves_an_already_current_config_untouched() {
+        let value = serde_json::to_value(GenerationConfig::default()).unwrap();
+
+        let (migrated, applied) = migrate(value.clone()).unwrap();
+
+        assert!(applied.is_empty());
+        assert_eq!(migrated, value);
+    }
+
+    #[test]
+    fn errors_instead_of_silently_defaulting_on_a_mismatched_schema() {
+        let mut value = serde_json::to_value(GenerationConfigV1_0::default()).unwrap();
+        value["plat_min_distance"] = Value::String("not a number".to_string());
+
+        assert!(migrate(value).is_err());
+    }
+}
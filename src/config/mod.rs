@@ -3,11 +3,27 @@ use crate::random::RandomDistConfig;
 use log::warn;
 use rust_embed::RustEmbed;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
 
+pub mod codec;
+pub mod migrate;
+mod prev;
+pub mod validation;
+
+pub use codec::BinCodecError;
+pub use validation::{Diagnostic, Severity};
+
+/// magic bytes identifying a [`GenerationConfig::save_bin`] file
+const GEN_CONFIG_MAGIC: &[u8; 4] = b"GMC1";
+
+/// magic bytes identifying a [`MapConfig::save_bin`] file
+const MAP_CONFIG_MAGIC: &[u8; 4] = b"GMP1";
+
 #[derive(RustEmbed)]
 #[folder = "data/gen_configs/"]
 pub struct GenerationConfigStorage;
@@ -52,6 +68,30 @@ impl MapConfig {
             .expect("failed to write to config file");
     }
 
+    pub fn load(path: &str) -> Result<MapConfig, ConfigLoadError> {
+        let serialized_from_file = fs::read_to_string(path).map_err(ConfigLoadError::Io)?;
+        serde_json::from_str(&serialized_from_file).map_err(ConfigLoadError::Parse)
+    }
+
+    /// bincode alternative to [`Self::save`] for embedding large waypoint sets compactly
+    pub fn save_bin(&self, path: &str) -> Result<(), BinCodecError> {
+        codec::write_magic_bincode(path, MAP_CONFIG_MAGIC, self)
+    }
+
+    pub fn load_bin(path: &str) -> Result<MapConfig, BinCodecError> {
+        codec::read_magic_bincode(path, MAP_CONFIG_MAGIC)
+    }
+
+    /// auto-detects whether `path` is JSON ([`Self::load`]) or [`Self::save_bin`]'s bincode
+    /// format, by magic bytes rather than file extension
+    pub fn load_auto(path: &str) -> Result<MapConfig, ConfigLoadError> {
+        if codec::has_magic(path, MAP_CONFIG_MAGIC).map_err(ConfigLoadError::Io)? {
+            return Self::load_bin(path).map_err(ConfigLoadError::Bin);
+        }
+
+        Self::load(path)
+    }
+
     /// This function defines the initial default config for actual map generator
     pub fn get_initial_config() -> MapConfig {
         let file = MapConfigStorage::get("small_s.json").unwrap();
@@ -165,42 +205,94 @@ pub struct GenerationConfig {
     pub lock_kernel_size: usize,
 }
 
-impl GenerationConfig {
-    /// returns an error if the configuration would result in a crash
-    pub fn validate(&self) -> Result<(), &'static str> {
-        // 1. Check that there is no inner kernel size of 0
-        for inner_size in self.inner_size_probs.values.as_ref().unwrap().iter() {
-            if *inner_size == 0 {
-                return Err("Invalid Config! (inner_size = 0)");
-            }
-        }
+/// result of [`GenerationConfig::load`]: the up-to-date config plus the migrations applied to
+/// reach it (empty if the file was already current)
+#[derive(Debug)]
+pub struct LoadedConfig {
+    pub config: GenerationConfig,
+    pub migrations_applied: Vec<String>,
+}
 
-        // 2. Check fade config
-        if self.fade_max_size == 0 || self.fade_min_size == 0 {
-            return Err("fade kernel sizes must be larger than zero");
-        }
+/// failure modes for `load`/`load_bin`/`load_auto` on [`GenerationConfig`] and [`MapConfig`]
+#[derive(Debug)]
+pub enum ConfigLoadError {
+    Io(std::io::Error),
+    Parse(serde_json::Error),
+    Bin(BinCodecError),
+}
 
-        // 3. Check subwaypoint config
-        if self.max_subwaypoint_dist <= 0.0 {
-            return Err("max subwaypoint distance must be >0");
+impl fmt::Display for ConfigLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigLoadError::Io(err) => write!(f, "failed to read config file: {err}"),
+            ConfigLoadError::Parse(err) => write!(f, "failed to deserialize config file: {err}"),
+            ConfigLoadError::Bin(err) => write!(f, "{err}"),
         }
+    }
+}
 
-        Ok(())
+impl GenerationConfig {
+    /// runs every validation rule and collects all diagnostics in one pass; generation should
+    /// refuse to start if [`validation::has_errors`] returns true for the result
+    pub fn validate(&self, map_config: &MapConfig) -> Vec<Diagnostic> {
+        validation::validate(self, map_config)
     }
 
+    /// serializes the config, stamping [`migrate::CURRENT_VERSION`] regardless of what version it
+    /// was loaded as
     pub fn save(&self, path: &str) {
+        let mut to_save = self.clone();
+        to_save.version = migrate::CURRENT_VERSION.to_string();
+
         let mut file = File::create(path).expect("failed to create config file");
-        let serialized = serde_json::to_string_pretty(self).expect("failed to serialize config");
+        let serialized =
+            serde_json::to_string_pretty(&to_save).expect("failed to serialize config");
         file.write_all(serialized.as_bytes())
             .expect("failed to write to config file");
     }
 
-    pub fn load(path: &str) -> GenerationConfig {
-        let serialized_from_file = fs::read_to_string(path).expect("failed to read config file");
-        let deserialized: GenerationConfig =
-            serde_json::from_str(&serialized_from_file).expect("failed to deserialize config file");
+    /// loads a config from `path`, migrating it to [`migrate::CURRENT_VERSION`] if needed
+    pub fn load(path: &str) -> Result<LoadedConfig, ConfigLoadError> {
+        let serialized_from_file = fs::read_to_string(path).map_err(ConfigLoadError::Io)?;
+        let raw: Value =
+            serde_json::from_str(&serialized_from_file).map_err(ConfigLoadError::Parse)?;
+
+        let (migrated, migrations_applied) =
+            migrate::migrate(raw).map_err(ConfigLoadError::Parse)?;
+        let config: GenerationConfig =
+            serde_json::from_value(migrated).map_err(ConfigLoadError::Parse)?;
+
+        Ok(LoadedConfig {
+            config,
+            migrations_applied,
+        })
+    }
+
+    /// bincode alternative to [`Self::save`] for caching configs compactly. Unlike [`Self::load`],
+    /// doesn't run [`migrate::migrate`] - bincode isn't self-describing, so this is for transient
+    /// caching of the current schema, not long-lived hand-edited presets.
+    pub fn save_bin(&self, path: &str) -> Result<(), BinCodecError> {
+        let mut to_save = self.clone();
+        to_save.version = migrate::CURRENT_VERSION.to_string();
+        codec::write_magic_bincode(path, GEN_CONFIG_MAGIC, &to_save)
+    }
 
-        deserialized
+    pub fn load_bin(path: &str) -> Result<GenerationConfig, BinCodecError> {
+        codec::read_magic_bincode(path, GEN_CONFIG_MAGIC)
+    }
+
+    /// auto-detects whether `path` is JSON ([`Self::load`]) or [`Self::save_bin`]'s bincode
+    /// format, by magic bytes rather than file extension
+    pub fn load_auto(path: &str) -> Result<LoadedConfig, ConfigLoadError> {
+        if codec::has_magic(path, GEN_CONFIG_MAGIC).map_err(ConfigLoadError::Io)? {
+            let config = Self::load_bin(path).map_err(ConfigLoadError::Bin)?;
+            return Ok(LoadedConfig {
+                config,
+                migrations_applied: Vec::new(),
+            });
+        }
+
+        Self::load(path)
     }
 
     pub fn get_all_configs() -> HashMap<String, GenerationConfig> {
@@ -209,7 +301,31 @@ impl GenerationConfig {
         for file_name in GenerationConfigStorage::iter() {
             let file = GenerationConfigStorage::get(&file_name).unwrap();
             let data = std::str::from_utf8(&file.data).unwrap();
-            match serde_json::from_str::<GenerationConfig>(data) {
+
+            let raw = match serde_json::from_str::<Value>(data) {
+                Ok(raw) => raw,
+                Err(e) => {
+                    warn!("couldn't parse gen config {}: {}", file_name, e);
+                    continue;
+                }
+            };
+
+            let (migrated, migrations_applied) = match migrate::migrate(raw) {
+                Ok(result) => result,
+                Err(e) => {
+                    warn!("couldn't migrate gen config {}: {}", file_name, e);
+                    continue;
+                }
+            };
+            if !migrations_applied.is_empty() {
+                warn!(
+                    "gen config {} is outdated, applied migrations: {}",
+                    file_name,
+                    migrations_applied.join(", ")
+                );
+            }
+
+            match serde_json::from_value::<GenerationConfig>(migrated) {
                 Ok(config) => {
                     configs.insert(config.name.clone(), config);
                 }
@@ -243,7 +359,7 @@ impl Default for GenerationConfig {
         GenerationConfig {
             name: "default".to_string(),
             description: None,
-            version: "1.0".to_string(),
+            version: migrate::CURRENT_VERSION.to_string(),
             inner_rad_mut_prob: 0.25,
             inner_size_mut_prob: 0.5,
             outer_rad_mut_prob: 0.25,
@@ -297,3 +413,46 @@ impl Default for MapConfig {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> String {
+        let mut path = env::temp_dir();
+        path.push(name);
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn gen_config_round_trips_through_bin_and_auto() {
+        let config = GenerationConfig::default();
+        let path = temp_path("gores_mapgen_test_gen_config.bin");
+
+        config.save_bin(&path).expect("save_bin should succeed");
+        let loaded = GenerationConfig::load_bin(&path).expect("load_bin should succeed");
+        assert_eq!(loaded, config);
+
+        let loaded = GenerationConfig::load_auto(&path).expect("load_auto should succeed");
+        assert_eq!(loaded.config, config);
+        assert!(loaded.migrations_applied.is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn map_config_round_trips_through_bin_and_auto() {
+        let config = MapConfig::default();
+        let path = temp_path("gores_mapgen_test_map_config.bin");
+
+        config.save_bin(&path).expect("save_bin should succeed");
+        let loaded = MapConfig::load_bin(&path).expect("load_bin should succeed");
+        assert_eq!(loaded, config);
+
+        let loaded = MapConfig::load_auto(&path).expect("load_auto should succeed");
+        assert_eq!(loaded, config);
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,6 @@
+//! Frozen historical `GenerationConfig` schemas, one module per released version. These exist
+//! purely as migration fixtures for [`super::migrate`] - application code should only ever use
+//! the current `GenerationConfig`.
+
+pub mod v1_0;
+pub mod v1_1;
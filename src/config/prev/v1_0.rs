@@ -0,0 +1,71 @@
+use crate::position::ShiftDirection;
+use crate::random::RandomDistConfig;
+use serde::{Deserialize, Serialize};
+
+/// frozen `GenerationConfig` schema for version "1.0" - never edit once a released preset may
+/// have been saved in this shape, add a new `vX_Y` module instead
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct GenerationConfigV1_0 {
+    pub name: String,
+    pub description: Option<String>,
+    pub version: String,
+    pub inner_rad_mut_prob: f32,
+    pub inner_size_mut_prob: f32,
+    pub outer_rad_mut_prob: f32,
+    pub outer_size_mut_prob: f32,
+    pub shift_weights: RandomDistConfig<ShiftDirection>,
+    pub plat_min_distance: usize,
+    pub plat_width_bounds: (usize, usize),
+    pub plat_height_bounds: (usize, usize),
+    pub plat_min_empty_height: usize,
+    pub momentum_prob: f32,
+    pub max_distance: f32,
+    pub waypoint_reached_dist: usize,
+    pub inner_size_probs: RandomDistConfig<usize>,
+    pub outer_margin_probs: RandomDistConfig<usize>,
+    pub circ_probs: RandomDistConfig<f32>,
+    pub skip_length_bounds: (usize, usize),
+    pub skip_min_spacing_sqr: usize,
+    pub max_level_skip: usize,
+    pub min_freeze_size: usize,
+    pub fade_steps: usize,
+    pub fade_max_size: usize,
+    pub fade_min_size: usize,
+    pub max_subwaypoint_dist: f32,
+    pub subwaypoint_max_shift_dist: f32,
+}
+
+impl Default for GenerationConfigV1_0 {
+    fn default() -> GenerationConfigV1_0 {
+        GenerationConfigV1_0 {
+            name: "default".to_string(),
+            description: None,
+            version: "1.0".to_string(),
+            inner_rad_mut_prob: 0.25,
+            inner_size_mut_prob: 0.5,
+            outer_rad_mut_prob: 0.25,
+            outer_size_mut_prob: 0.5,
+            shift_weights: RandomDistConfig::new(None, vec![0.4, 0.22, 0.2, 0.18]),
+            plat_min_distance: 75,
+            plat_width_bounds: (3, 5),
+            plat_height_bounds: (1, 2),
+            plat_min_empty_height: 4,
+            momentum_prob: 0.01,
+            max_distance: 3.0,
+            waypoint_reached_dist: 250,
+            inner_size_probs: RandomDistConfig::new(Some(vec![3, 5]), vec![0.25, 0.75]),
+            outer_margin_probs: RandomDistConfig::new(Some(vec![0, 2]), vec![0.5, 0.5]),
+            circ_probs: RandomDistConfig::new(Some(vec![0.0, 0.6, 0.8]), vec![0.75, 0.15, 0.05]),
+            skip_min_spacing_sqr: 45,
+            skip_length_bounds: (3, 11),
+            max_level_skip: 90,
+            min_freeze_size: 0,
+            fade_steps: 60,
+            fade_max_size: 6,
+            fade_min_size: 3,
+            max_subwaypoint_dist: 50.0,
+            subwaypoint_max_shift_dist: 5.0,
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use super::{GenerationConfig, MapConfig};
+
+/// how serious a [`Diagnostic`] is - `Error` blocks generation, `Warning` doesn't
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// a single problem found in a `GenerationConfig`/`MapConfig` pair by a [`Rule`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// name of the offending field, e.g. `"fade_min_size"`
+    pub field: &'static str,
+    pub message: String,
+}
+
+impl Diagnostic {
+    fn error(field: &'static str, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Error,
+            field,
+            message: message.into(),
+        }
+    }
+
+    fn warning(field: &'static str, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            severity: Severity::Warning,
+            field,
+            message: message.into(),
+        }
+    }
+}
+
+/// a single, independent config check, owning the field(s) it reports on
+trait Rule {
+    fn check(&self, config: &GenerationConfig, map_config: &MapConfig) -> Vec<Diagnostic>;
+}
+
+struct InnerKernelSizeRule;
+
+impl Rule for InnerKernelSizeRule {
+    fn check(&self, config: &GenerationConfig, _map_config: &MapConfig) -> Vec<Diagnostic> {
+        match &config.inner_size_probs.values {
+            Some(values) if values.iter().any(|size| *size == 0) => {
+                vec![Diagnostic::error(
+                    "inner_size_probs",
+                    "inner kernel size must not be 0",
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+struct FadeSizeRule;
+
+impl Rule for FadeSizeRule {
+    fn check(&self, config: &GenerationConfig, _map_config: &MapConfig) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+
+        if config.fade_max_size == 0 {
+            diagnostics.push(Diagnostic::error(
+                "fade_max_size",
+                "fade kernel sizes must be larger than zero",
+            ));
+        }
+        if config.fade_min_size == 0 {
+            diagnostics.push(Diagnostic::error(
+                "fade_min_size",
+                "fade kernel sizes must be larger than zero",
+            ));
+        }
+        if config.fade_min_size > config.fade_max_size {
+            diagnostics.push(Diagnostic::warning(
+                "fade_min_size",
+                format!(
+                    "fade_min_size ({}) is larger than fade_max_size ({}), fading will never shrink",
+                    config.fade_min_size, config.fade_max_size
+                ),
+            ));
+        }
+
+        diagnostics
+    }
+}
+
+struct SubwaypointDistRule;
+
+impl Rule for SubwaypointDistRule {
+    fn check(&self, config: &GenerationConfig, _map_config: &MapConfig) -> Vec<Diagnostic> {
+        if config.max_subwaypoint_dist <= 0.0 {
+            vec![Diagnostic::error(
+                "max_subwaypoint_dist",
+                "max subwaypoint distance must be >0",
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct SkipLengthBoundsRule;
+
+impl Rule for SkipLengthBoundsRule {
+    fn check(&self, config: &GenerationConfig, _map_config: &MapConfig) -> Vec<Diagnostic> {
+        let (min, max) = config.skip_length_bounds;
+        if min > max {
+            vec![Diagnostic::warning(
+                "skip_length_bounds",
+                format!("skip_length_bounds min ({min}) is larger than max ({max})"),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct WaypointReachedDistRule;
+
+impl Rule for WaypointReachedDistRule {
+    fn check(&self, config: &GenerationConfig, map_config: &MapConfig) -> Vec<Diagnostic> {
+        let map_diagonal =
+            ((map_config.width.pow(2) + map_config.height.pow(2)) as f64).sqrt() as usize;
+
+        if config.waypoint_reached_dist > map_diagonal {
+            vec![Diagnostic::warning(
+                "waypoint_reached_dist",
+                format!(
+                    "waypoint_reached_dist ({}) is larger than the map diagonal ({map_diagonal}), \
+                     waypoints may be skipped instantly",
+                    config.waypoint_reached_dist
+                ),
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+struct WaypointsNotEmptyRule;
+
+impl Rule for WaypointsNotEmptyRule {
+    fn check(&self, _config: &GenerationConfig, map_config: &MapConfig) -> Vec<Diagnostic> {
+        if map_config.waypoints.is_empty() {
+            vec![Diagnostic::error(
+                "waypoints",
+                "map config has no waypoints, generation has no spawn to start from",
+            )]
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+/// checks that a probability vector sums to roughly 1.0
+fn check_prob_sum(field: &'static str, probs: &[f32]) -> Option<Diagnostic> {
+    const TOLERANCE: f32 = 0.01;
+
+    let sum: f32 = probs.iter().sum();
+    if (sum - 1.0).abs() > TOLERANCE {
+        Some(Diagnostic::warning(
+            field,
+            format!("{field} probabilities sum to {sum}, expected ~1.0"),
+        ))
+    } else {
+        None
+    }
+}
+
+struct ProbabilitySumRule;
+
+impl Rule for ProbabilitySumRule {
+    fn check(&self, config: &GenerationConfig, _map_config: &MapConfig) -> Vec<Diagnostic> {
+        [
+            check_prob_sum("shift_weights", &config.shift_weights.probs),
+            check_prob_sum("circ_probs", &config.circ_probs.probs),
+            check_prob_sum("inner_size_probs", &config.inner_size_probs.probs),
+            check_prob_sum("outer_margin_probs", &config.outer_margin_probs.probs),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+fn rules() -> Vec<Box<dyn Rule>> {
+    vec![
+        Box::new(InnerKernelSizeRule),
+        Box::new(FadeSizeRule),
+        Box::new(SubwaypointDistRule),
+        Box::new(SkipLengthBoundsRule),
+        Box::new(WaypointReachedDistRule),
+        Box::new(WaypointsNotEmptyRule),
+        Box::new(ProbabilitySumRule),
+    ]
+}
+
+/// runs every registered [`Rule`] and collects all diagnostics in one pass
+pub fn validate(config: &GenerationConfig, map_config: &MapConfig) -> Vec<Diagnostic> {
+    rules()
+        .iter()
+        .flat_map(|rule| rule.check(config, map_config))
+        .collect()
+}
+
+/// whether `diagnostics` contains anything that should block generation
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity == Severity::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_defaults_produce_no_diagnostics() {
+        let config = GenerationConfig::default();
+        let map_config = MapConfig::default();
+
+        assert!(validate(&config, &map_config).is_empty());
+    }
+
+    #[test]
+    fn zero_fade_size_is_an_error() {
+        let mut config = GenerationConfig::default();
+        config.fade_max_size = 0;
+
+        let diagnostics = validate(&config, &MapConfig::default());
+
+        assert!(has_errors(&diagnostics));
+        assert!(diagnostics.iter().any(|d| d.field == "fade_max_size"));
+    }
+
+    #[test]
+    fn inverted_fade_bounds_is_a_warning_not_an_error() {
+        let mut config = GenerationConfig::default();
+        config.fade_min_size = config.fade_max_size + 1;
+
+        let diagnostics = validate(&config, &MapConfig::default());
+
+        assert!(!has_errors(&diagnostics));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field == "fade_min_size" && d.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn empty_waypoints_is_an_error() {
+        let mut map_config = MapConfig::default();
+        map_config.waypoints = Vec::new();
+
+        let diagnostics = validate(&GenerationConfig::default(), &map_config);
+
+        assert!(has_errors(&diagnostics));
+        assert!(diagnostics.iter().any(|d| d.field == "waypoints"));
+    }
+
+    #[test]
+    fn non_positive_max_subwaypoint_dist_is_an_error() {
+        let mut config = GenerationConfig::default();
+        config.max_subwaypoint_dist = 0.0;
+
+        let diagnostics = validate(&config, &MapConfig::default());
+
+        assert!(has_errors(&diagnostics));
+    }
+
+    #[test]
+    fn unnormalized_probabilities_produce_a_warning() {
+        let mut config = GenerationConfig::default();
+        config.circ_probs.probs = vec![0.1, 0.1];
+
+        let diagnostics = validate(&config, &MapConfig::default());
+
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.field == "circ_probs" && d.severity == Severity::Warning));
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    config::GenerationConfig,
+    config::{GenerationConfig, MapConfig},
     kernel::Kernel,
     map::{BlockType, Map},
     position::Position,
@@ -16,10 +16,15 @@ pub struct Generator {
 }
 
 impl Generator {
-    /// derive a initial generator state based on a GenerationConfig
-    pub fn new(config: &GenerationConfig, seed: u64) -> Generator {
-        let spawn = Position::new(50, 250);
-        let map = Map::new(300, 300, BlockType::Hookable, spawn.clone());
+    /// derive a initial generator state based on a GenerationConfig and MapConfig
+    pub fn new(config: &GenerationConfig, map_config: &MapConfig, seed: u64) -> Generator {
+        let spawn = map_config.waypoints[0].clone();
+        let map = Map::new(
+            map_config.width,
+            map_config.height,
+            BlockType::Hookable,
+            spawn.clone(),
+        );
         let init_inner_kernel = Kernel::new(config.inner_size_bounds.1, 0.0);
         let init_outer_kernel = Kernel::new(config.outer_size_bounds.1, 0.1);
         let walker = CuteWalker::new(spawn, init_inner_kernel, init_outer_kernel, config);
@@ -136,8 +141,9 @@ impl Generator {
         max_steps: usize,
         seed: u64,
         config: &GenerationConfig,
+        map_config: &MapConfig,
     ) -> Result<Map, &'static str> {
-        let mut gen = Generator::new(&config, seed);
+        let mut gen = Generator::new(config, map_config, seed);
 
         for _ in 0..max_steps {
             if gen.walker.finished {
@@ -0,0 +1,208 @@
+use crate::map::{BlockType, Map};
+use crate::position::Position;
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
+
+/// magic bytes identifying a [`save_map`] export
+const MAP_MAGIC: &[u8; 4] = b"GMMZ";
+
+/// sanity cap on `width`/`height` read from a file header, to reject corrupt/hostile headers
+/// before they reach an allocation
+const MAX_DIMENSION: usize = 1 << 20;
+
+/// size stats for a [`save_map`] export
+#[derive(Debug, Clone, Copy)]
+pub struct CompressedMapStats {
+    pub raw_size: usize,
+    pub compressed_size: usize,
+}
+
+fn block_to_byte(block: &BlockType) -> u8 {
+    match block {
+        BlockType::Hookable => 0,
+        BlockType::Empty => 1,
+        BlockType::Freeze => 2,
+        BlockType::Start => 3,
+        BlockType::Finish => 4,
+        BlockType::Spawn => 5,
+    }
+}
+
+fn byte_to_block(byte: u8) -> BlockType {
+    match byte {
+        1 => BlockType::Empty,
+        2 => BlockType::Freeze,
+        3 => BlockType::Start,
+        4 => BlockType::Finish,
+        5 => BlockType::Spawn,
+        _ => BlockType::Hookable,
+    }
+}
+
+/// packs `map`'s grid one byte per cell (row-major) and zlib-compresses it, without touching disk
+fn pack_and_compress(map: &Map) -> io::Result<(Vec<u8>, Vec<u8>)> {
+    let mut raw = Vec::with_capacity(map.width * map.height);
+    for y in 0..map.height {
+        for x in 0..map.width {
+            raw.push(block_to_byte(&map.grid[[x, y]]));
+        }
+    }
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&raw)?;
+    let compressed = encoder.finish()?;
+
+    Ok((raw, compressed))
+}
+
+/// computes how large `map` would be as a [`save_map`] export, without writing anything
+pub fn compressed_size(map: &Map) -> io::Result<CompressedMapStats> {
+    let (raw, compressed) = pack_and_compress(map)?;
+    Ok(CompressedMapStats {
+        raw_size: raw.len(),
+        compressed_size: compressed.len(),
+    })
+}
+
+/// packs `map`'s grid one byte per cell (row-major), zlib-compresses it, and writes it to `path`
+/// behind [`MAP_MAGIC`] so [`load_map`] can auto-detect the format
+pub fn save_map(map: &Map, path: &str) -> io::Result<CompressedMapStats> {
+    let (raw, compressed) = pack_and_compress(map)?;
+
+    let mut file = File::create(path)?;
+    file.write_all(MAP_MAGIC)?;
+    file.write_all(&(map.width as u64).to_le_bytes())?;
+    file.write_all(&(map.height as u64).to_le_bytes())?;
+    file.write_all(&(map.spawn.x as u64).to_le_bytes())?;
+    file.write_all(&(map.spawn.y as u64).to_le_bytes())?;
+    file.write_all(&compressed)?;
+
+    Ok(CompressedMapStats {
+        raw_size: raw.len(),
+        compressed_size: compressed.len(),
+    })
+}
+
+/// loads a map written by [`save_map`], or `Ok(None)` if `path` doesn't start with [`MAP_MAGIC`]
+pub fn load_map(path: &str) -> io::Result<Option<Map>> {
+    let mut file = File::open(path)?;
+    let mut data = Vec::new();
+    file.read_to_end(&mut data)?;
+
+    let header_len = MAP_MAGIC.len() + 4 * 8;
+    if data.len() < header_len || data[..MAP_MAGIC.len()] != *MAP_MAGIC {
+        return Ok(None);
+    }
+
+    let read_u64 = |offset: usize| {
+        u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize
+    };
+
+    let mut offset = MAP_MAGIC.len();
+    let width = read_u64(offset);
+    offset += 8;
+    let height = read_u64(offset);
+    offset += 8;
+    let spawn_x = read_u64(offset);
+    offset += 8;
+    let spawn_y = read_u64(offset);
+    offset += 8;
+
+    if width == 0 || height == 0 || width > MAX_DIMENSION || height > MAX_DIMENSION {
+        return Ok(None);
+    }
+    let Some(expected_len) = width.checked_mul(height) else {
+        return Ok(None);
+    };
+    if spawn_x >= width || spawn_y >= height {
+        return Ok(None);
+    }
+
+    // bound the decompressed read to the declared grid size (plus one byte) so a mismatched or
+    // hostile payload can't inflate far past it before the length check below rejects it
+    let decoder = ZlibDecoder::new(&data[offset..]);
+    let mut raw = Vec::new();
+    decoder.take(expected_len as u64 + 1).read_to_end(&mut raw)?;
+
+    if raw.len() != expected_len {
+        return Ok(None);
+    }
+
+    let spawn = Position::new(spawn_x, spawn_y);
+    let mut map = Map::new(width, height, BlockType::Hookable, spawn);
+    for y in 0..height {
+        for x in 0..width {
+            map.grid[[x, y]] = byte_to_block(raw[y * width + x]);
+        }
+    }
+
+    Ok(Some(map))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn temp_path(name: &str) -> String {
+        let mut path = env::temp_dir();
+        path.push(name);
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn round_trips_through_save_and_load() {
+        let spawn = Position::new(1, 2);
+        let mut map = Map::new(4, 3, BlockType::Hookable, spawn);
+        map.grid[[1, 2]] = BlockType::Spawn;
+        map.grid[[2, 2]] = BlockType::Empty;
+
+        let path = temp_path("gores_mapgen_test_round_trip.bin");
+        save_map(&map, &path).expect("save_map should succeed");
+
+        let loaded = load_map(&path)
+            .expect("load_map should succeed")
+            .expect("file should be recognized as a map export");
+
+        assert!(loaded.width == map.width);
+        assert!(loaded.height == map.height);
+        assert!(loaded.spawn == map.spawn);
+        for y in 0..map.height {
+            for x in 0..map.width {
+                assert!(loaded.grid[[x, y]] == map.grid[[x, y]]);
+            }
+        }
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn rejects_file_whose_payload_does_not_match_its_header() {
+        let path = temp_path("gores_mapgen_test_truncated.bin");
+
+        // a validly zlib-compressed payload, but far too short for the 10x10 grid the header
+        // claims - the kind of mismatch a bit-flipped or hand-crafted header would cause
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&[0u8; 3]).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut contents = MAP_MAGIC.to_vec();
+        contents.extend_from_slice(&10u64.to_le_bytes());
+        contents.extend_from_slice(&10u64.to_le_bytes());
+        contents.extend_from_slice(&0u64.to_le_bytes());
+        contents.extend_from_slice(&0u64.to_le_bytes());
+        contents.extend_from_slice(&compressed);
+        std::fs::write(&path, &contents).unwrap();
+
+        let result = load_map(&path).expect("a size mismatch should not be an io error");
+        assert!(result.is_none());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
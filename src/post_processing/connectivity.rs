@@ -0,0 +1,279 @@
+use crate::map::{BlockType, Map};
+use crate::position::Position;
+use std::collections::{HashMap, VecDeque};
+
+type Node = usize;
+
+/// infinite-capacity stand-in, kept well below `i64::MAX` so augmentation sums can't overflow
+const INFINITE_CAPACITY: i64 = i64::MAX / 4;
+
+/// result of [`analyze`]: how many independent routes exist between two cells, and where the
+/// narrowest point between them is
+#[derive(Debug, Clone)]
+pub struct RedundancyReport {
+    /// number of vertex-disjoint paths between the two cells (0 if disconnected)
+    pub redundancy: usize,
+    /// saturated edges of the min cut, as (from, to) grid positions
+    pub min_cut: Vec<(Position, Position)>,
+}
+
+/// quantifies how many vertex-disjoint paths exist between `spawn` and `finish`, to detect
+/// whether `skip_length_bounds`/`max_level_skip` merged otherwise separate parts of a map. Each
+/// traversable cell is split into an in-node/out-node pair joined by a unit-capacity edge;
+/// max-flow from spawn's out-node to finish's in-node gives the route count
+pub fn analyze(map: &Map, spawn: &Position, finish: &Position) -> RedundancyReport {
+    let width = map.width;
+    let height = map.height;
+    let node_count = width * height * 2;
+
+    let in_node = |x: usize, y: usize| 2 * (y * width + x);
+    let out_node = |x: usize, y: usize| 2 * (y * width + x) + 1;
+    let is_traversable = |pos: &Position| {
+        matches!(
+            map.grid[[pos.x, pos.y]],
+            BlockType::Empty | BlockType::Freeze
+        )
+    };
+
+    let mut capacity: HashMap<(Node, Node), i64> = HashMap::new();
+    let mut adjacency: Vec<Vec<Node>> = vec![Vec::new(); node_count];
+
+    let mut add_edge = |capacity: &mut HashMap<(Node, Node), i64>,
+                        adjacency: &mut Vec<Vec<Node>>,
+                        from: Node,
+                        to: Node,
+                        cap: i64| {
+        capacity.insert((from, to), cap);
+        capacity.entry((to, from)).or_insert(0);
+        adjacency[from].push(to);
+        adjacency[to].push(from);
+    };
+
+    for y in 0..height {
+        for x in 0..width {
+            let pos = Position::new(x, y);
+            if !is_traversable(&pos) {
+                continue;
+            }
+
+            add_edge(
+                &mut capacity,
+                &mut adjacency,
+                in_node(x, y),
+                out_node(x, y),
+                1,
+            );
+
+            for (dx, dy) in [(1isize, 0isize), (0, 1)] {
+                let neighbor_x = x as isize + dx;
+                let neighbor_y = y as isize + dy;
+                if neighbor_x < 0
+                    || neighbor_y < 0
+                    || neighbor_x as usize >= width
+                    || neighbor_y as usize >= height
+                {
+                    continue;
+                }
+
+                let (nx, ny) = (neighbor_x as usize, neighbor_y as usize);
+                if !is_traversable(&Position::new(nx, ny)) {
+                    continue;
+                }
+
+                add_edge(
+                    &mut capacity,
+                    &mut adjacency,
+                    out_node(x, y),
+                    in_node(nx, ny),
+                    INFINITE_CAPACITY,
+                );
+                add_edge(
+                    &mut capacity,
+                    &mut adjacency,
+                    out_node(nx, ny),
+                    in_node(x, y),
+                    INFINITE_CAPACITY,
+                );
+            }
+        }
+    }
+
+    let source = out_node(spawn.x, spawn.y);
+    let sink = in_node(finish.x, finish.y);
+
+    // spawn and finish's vertex-capacity edges don't constrain a direct edge between them, so if
+    // they're 4-adjacent this one is left at INFINITE_CAPACITY - cap it to the 1 path it actually represents
+    if let Some(direct) = capacity.get_mut(&(source, sink)) {
+        *direct = 1;
+    }
+
+    let redundancy = edmonds_karp(&mut capacity, &adjacency, source, sink, node_count);
+    let min_cut = min_cut_edges(&capacity, &adjacency, source, node_count, width);
+
+    RedundancyReport {
+        redundancy: redundancy as usize,
+        min_cut,
+    }
+}
+
+/// finds an augmenting path from `source` to `sink` via BFS (the Edmonds-Karp shortest-path rule)
+fn bfs_augmenting_path(
+    capacity: &HashMap<(Node, Node), i64>,
+    adjacency: &[Vec<Node>],
+    source: Node,
+    sink: Node,
+    node_count: usize,
+) -> Option<Vec<Node>> {
+    let mut parent: Vec<Option<Node>> = vec![None; node_count];
+    let mut visited = vec![false; node_count];
+    let mut queue = VecDeque::new();
+
+    visited[source] = true;
+    queue.push_back(source);
+
+    while let Some(node) = queue.pop_front() {
+        if node == sink {
+            break;
+        }
+
+        for &next in &adjacency[node] {
+            if !visited[next] && *capacity.get(&(node, next)).unwrap_or(&0) > 0 {
+                visited[next] = true;
+                parent[next] = Some(node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    if !visited[sink] {
+        return None;
+    }
+
+    let mut path = vec![sink];
+    while let Some(prev) = parent[*path.last().unwrap()] {
+        path.push(prev);
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+fn edmonds_karp(
+    capacity: &mut HashMap<(Node, Node), i64>,
+    adjacency: &[Vec<Node>],
+    source: Node,
+    sink: Node,
+    node_count: usize,
+) -> i64 {
+    let mut max_flow = 0;
+
+    while let Some(path) = bfs_augmenting_path(capacity, adjacency, source, sink, node_count) {
+        let bottleneck = path
+            .windows(2)
+            .map(|edge| *capacity.get(&(edge[0], edge[1])).unwrap_or(&0))
+            .min()
+            .unwrap_or(0);
+
+        if bottleneck <= 0 {
+            break;
+        }
+
+        for edge in path.windows(2) {
+            *capacity.entry((edge[0], edge[1])).or_insert(0) -= bottleneck;
+            *capacity.entry((edge[1], edge[0])).or_insert(0) += bottleneck;
+        }
+
+        max_flow += bottleneck;
+    }
+
+    max_flow
+}
+
+/// saturated edges from nodes reachable from `source` in the final residual graph to those that
+/// aren't - the min cut, by the max-flow/min-cut theorem
+fn min_cut_edges(
+    capacity: &HashMap<(Node, Node), i64>,
+    adjacency: &[Vec<Node>],
+    source: Node,
+    node_count: usize,
+    width: usize,
+) -> Vec<(Position, Position)> {
+    let mut visited = vec![false; node_count];
+    let mut queue = VecDeque::new();
+    visited[source] = true;
+    queue.push_back(source);
+
+    while let Some(node) = queue.pop_front() {
+        for &next in &adjacency[node] {
+            if !visited[next] && *capacity.get(&(node, next)).unwrap_or(&0) > 0 {
+                visited[next] = true;
+                queue.push_back(next);
+            }
+        }
+    }
+
+    let pos_of = |node: Node| {
+        let cell = node / 2;
+        Position::new(cell % width, cell / width)
+    };
+
+    let mut cut = Vec::new();
+    for (node, neighbors) in adjacency.iter().enumerate() {
+        if !visited[node] {
+            continue;
+        }
+        for &next in neighbors {
+            if visited[next] {
+                continue;
+            }
+            if capacity.get(&(node, next)).copied().unwrap_or(0) == 0 {
+                cut.push((pos_of(node), pos_of(next)));
+            }
+        }
+    }
+
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::Map;
+
+    fn corridor(length: usize) -> Map {
+        Map::new(length, 1, BlockType::Empty, Position::new(0, 0))
+    }
+
+    #[test]
+    fn adjacent_spawn_and_finish_has_redundancy_one() {
+        let map = corridor(2);
+        let spawn = Position::new(0, 0);
+        let finish = Position::new(1, 0);
+
+        let report = analyze(&map, &spawn, &finish);
+
+        assert_eq!(report.redundancy, 1);
+    }
+
+    #[test]
+    fn long_corridor_has_redundancy_one() {
+        let map = corridor(10);
+        let spawn = Position::new(0, 0);
+        let finish = Position::new(9, 0);
+
+        let report = analyze(&map, &spawn, &finish);
+
+        assert_eq!(report.redundancy, 1);
+    }
+
+    #[test]
+    fn open_grid_has_multiple_vertex_disjoint_routes() {
+        let map = Map::new(3, 3, BlockType::Empty, Position::new(0, 1));
+        let spawn = Position::new(0, 1);
+        let finish = Position::new(2, 1);
+
+        let report = analyze(&map, &spawn, &finish);
+
+        assert_eq!(report.redundancy, 3);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::map::{BlockType, Map};
+use crate::position::Position;
+use std::collections::VecDeque;
+
+pub mod connectivity;
+
+/// Flood-fills the playable space (`Empty`/`Freeze` cells) from `spawn` and reports whether
+/// `finish` is reachable. This is a cheap sanity check used by [`crate::sweep`] to classify
+/// degenerate maps before anything more expensive runs; it does not say *how* connected the map
+/// is, only whether it is at all.
+pub fn is_connected(map: &Map, spawn: &Position, finish: &Position) -> bool {
+    let is_traversable = |pos: &Position| {
+        matches!(
+            map.grid[[pos.x, pos.y]],
+            BlockType::Empty | BlockType::Freeze
+        )
+    };
+
+    let mut visited = vec![vec![false; map.height]; map.width];
+    let mut queue = VecDeque::new();
+
+    queue.push_back(spawn.clone());
+    visited[spawn.x][spawn.y] = true;
+
+    while let Some(pos) = queue.pop_front() {
+        if pos == *finish {
+            return true;
+        }
+
+        for (dx, dy) in [(0, 1), (0, -1), (1, 0), (-1, 0)] {
+            let neighbor_x = pos.x as isize + dx;
+            let neighbor_y = pos.y as isize + dy;
+            if neighbor_x < 0
+                || neighbor_y < 0
+                || neighbor_x as usize >= map.width
+                || neighbor_y as usize >= map.height
+            {
+                continue;
+            }
+
+            let neighbor = Position::new(neighbor_x as usize, neighbor_y as usize);
+            if !visited[neighbor.x][neighbor.y] && is_traversable(&neighbor) {
+                visited[neighbor.x][neighbor.y] = true;
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    false
+}
@@ -0,0 +1,194 @@
+use crate::config::{validation, Diagnostic, GenerationConfig, MapConfig};
+use crate::generator::Generator;
+use crate::map_codec::{self, CompressedMapStats};
+use crate::post_processing;
+
+use rayon::prelude::*;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// why a single seed in a [`sweep`] failed to produce a usable map
+#[derive(Debug, Clone, PartialEq)]
+pub enum FailureCause {
+    /// the config itself is invalid, independent of any seed
+    ValidateError(Vec<Diagnostic>),
+    /// the walker could not make progress and hit `pos_lock_max_delay`
+    Stuck,
+    /// `max_steps` was reached before the walker finished
+    Unfinished,
+    /// spawn and finish ended up in disconnected parts of the map
+    Disconnected,
+    /// route redundancy (see [`post_processing::connectivity`]) exceeded the configured
+    /// threshold, meaning skips merged separate sections of the map
+    ExcessiveRedundancy(usize),
+}
+
+impl FailureCause {
+    /// stable, snake_case label for grouping/reporting
+    pub fn label(&self) -> &'static str {
+        match self {
+            FailureCause::ValidateError(_) => "validate_error",
+            FailureCause::Stuck => "stuck",
+            FailureCause::Unfinished => "unfinished",
+            FailureCause::Disconnected => "disconnected",
+            FailureCause::ExcessiveRedundancy(_) => "excessive_redundancy",
+        }
+    }
+}
+
+/// outcome of generating a single seed
+#[derive(Debug, Clone)]
+pub struct SweepResult {
+    pub seed: u64,
+    /// SHA-256 hex digest of the `GenerationConfig` used
+    pub config_hash: String,
+    pub outcome: Result<CompressedMapStats, FailureCause>,
+}
+
+/// aggregate result of a [`sweep`] run
+#[derive(Debug, Clone, Default)]
+pub struct SweepReport {
+    pub results: Vec<SweepResult>,
+}
+
+impl SweepReport {
+    pub fn success_rate(&self) -> f64 {
+        if self.results.is_empty() {
+            return 0.0;
+        }
+
+        let successes = self.results.iter().filter(|r| r.outcome.is_ok()).count();
+        successes as f64 / self.results.len() as f64
+    }
+
+    /// number of failures per [`FailureCause::label`]
+    pub fn failure_breakdown(&self) -> HashMap<&'static str, usize> {
+        let mut breakdown = HashMap::new();
+
+        for result in &self.results {
+            if let Err(cause) = &result.outcome {
+                *breakdown.entry(cause.label()).or_insert(0) += 1;
+            }
+        }
+
+        breakdown
+    }
+
+    /// average compressed size, in bytes, across successful maps - `None` if none succeeded
+    pub fn average_compressed_size(&self) -> Option<f64> {
+        let sizes: Vec<usize> = self
+            .results
+            .iter()
+            .filter_map(|r| r.outcome.as_ref().ok())
+            .map(|stats| stats.compressed_size)
+            .collect();
+
+        if sizes.is_empty() {
+            return None;
+        }
+
+        Some(sizes.iter().sum::<usize>() as f64 / sizes.len() as f64)
+    }
+}
+
+/// hashes a `GenerationConfig`'s JSON representation with SHA-256, stable across builds unlike
+/// `DefaultHasher`
+fn hash_config(config: &GenerationConfig) -> String {
+    let serialized =
+        serde_json::to_string(config).expect("GenerationConfig must always be serializable");
+    let digest = Sha256::digest(serialized.as_bytes());
+    format!("{digest:x}")
+}
+
+/// generates `seeds` in parallel against `config`/`map_config` and classifies every failure.
+/// `max_redundancy`, if set, rejects maps whose route redundancy (see
+/// [`post_processing::connectivity`]) exceeds it
+pub fn sweep(
+    config: &GenerationConfig,
+    map_config: &MapConfig,
+    seeds: Range<u64>,
+    max_steps: usize,
+    max_redundancy: Option<usize>,
+) -> SweepReport {
+    let config_hash = hash_config(config);
+
+    let diagnostics = config.validate(map_config);
+    if validation::has_errors(&diagnostics) {
+        let results = seeds
+            .map(|seed| SweepResult {
+                seed,
+                config_hash: config_hash.clone(),
+                outcome: Err(FailureCause::ValidateError(diagnostics.clone())),
+            })
+            .collect();
+        return SweepReport { results };
+    }
+
+    let results = seeds
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|seed| SweepResult {
+            seed,
+            config_hash: config_hash.clone(),
+            outcome: sweep_one(config, map_config, seed, max_steps, max_redundancy),
+        })
+        .collect();
+
+    SweepReport { results }
+}
+
+fn sweep_one(
+    config: &GenerationConfig,
+    map_config: &MapConfig,
+    seed: u64,
+    max_steps: usize,
+    max_redundancy: Option<usize>,
+) -> Result<CompressedMapStats, FailureCause> {
+    let mut gen = Generator::new(config, map_config, seed);
+
+    for _ in 0..max_steps {
+        if gen.walker.finished {
+            break;
+        }
+        gen.step(config).map_err(|_| FailureCause::Stuck)?;
+    }
+
+    if !gen.walker.finished {
+        return Err(FailureCause::Unfinished);
+    }
+
+    gen.post_processing();
+
+    let spawn = gen.map.spawn.clone();
+    let finish = gen.walker.pos.clone();
+
+    if !post_processing::is_connected(&gen.map, &spawn, &finish) {
+        return Err(FailureCause::Disconnected);
+    }
+
+    if let Some(max_redundancy) = max_redundancy {
+        let redundancy = post_processing::connectivity::analyze(&gen.map, &spawn, &finish).redundancy;
+        if redundancy > max_redundancy {
+            return Err(FailureCause::ExcessiveRedundancy(redundancy));
+        }
+    }
+
+    Ok(map_codec::compressed_size(&gen.map)
+        .expect("compressing an in-memory buffer cannot fail"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hash_config_is_deterministic_and_sensitive_to_changes() {
+        let config = GenerationConfig::default();
+        let mut other = config.clone();
+        other.momentum_prob += 0.01;
+
+        assert_eq!(hash_config(&config), hash_config(&config));
+        assert_ne!(hash_config(&config), hash_config(&other));
+    }
+}